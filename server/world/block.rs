@@ -0,0 +1,62 @@
+/// A block definition, registered once in the `Registry` and looked up by ID
+/// everywhere else (meshing, lighting, physics).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Block {
+    pub id: u32,
+    pub name: String,
+
+    /// Whether the mesher should treat this block as see-through. Lighting does
+    /// not read this flag; see `light_propagates` and `sunlight_propagates` for
+    /// light transmission.
+    pub is_transparent: bool,
+
+    /// Whether colored torch light can flood through this block, e.g. thin
+    /// leaves that block daylight but still let a nearby lamp shine through.
+    pub light_propagates: bool,
+
+    /// Whether sunlight can flood through this block, independent of
+    /// `light_propagates`, e.g. a one-way tinted panel that passes daylight
+    /// but not torchlight.
+    pub sunlight_propagates: bool,
+
+    /// Whether this block emits colored torch light.
+    pub is_light: bool,
+    pub red_light_level: u32,
+    pub green_light_level: u32,
+    pub blue_light_level: u32,
+
+    /// How many light levels a neighbor's light loses when it floods into this
+    /// block; this is the whole per-step falloff, raised to at least 1 except
+    /// for sunlight traveling straight down. Dense-but-transparent media
+    /// (water, ice, fog) set this above 1 to dim light faster than ordinary
+    /// air.
+    pub light_reduction: u32,
+
+    /// Per-channel (red, green, blue) attenuation applied to torchlight
+    /// crossing this block; a non-zero entry replaces `light_reduction` for
+    /// that channel, a zero entry leaves `light_reduction` in place. A red glass pane
+    /// would set this to something like `[1, 8, 8]` so it barely dims red
+    /// light but heavily filters out green and blue, tinting whatever shines
+    /// through it. White sunlight accumulates this per-channel when it
+    /// crosses the block, seeding colored shadows. `[0, 0, 0]` means no
+    /// filtering.
+    pub light_filter: [u32; 3],
+}
+
+impl Default for Block {
+    fn default() -> Self {
+        Self {
+            id: 0,
+            name: "Air".to_owned(),
+            is_transparent: true,
+            light_propagates: true,
+            sunlight_propagates: true,
+            is_light: false,
+            red_light_level: 0,
+            green_light_level: 0,
+            blue_light_level: 0,
+            light_reduction: 1,
+            light_filter: [0, 0, 0],
+        }
+    }
+}
@@ -19,6 +19,11 @@ pub const VOXEL_NEIGHBORS: [[i32; 3]; 6] = [
     [0, -1, 0],
 ];
 
+/// The horizontal subset of `VOXEL_NEIGHBORS` that actually border a neighboring chunk.
+/// Chunks are addressed by `Vec2<i32>` and span the full world height, so there is no
+/// chunk above or below to relight a border against - only `+x`, `-x`, `+z`, `-z` apply.
+pub const CHUNK_BORDER_DIRECTIONS: [[i32; 3]; 4] = [[1, 0, 0], [-1, 0, 0], [0, 0, 1], [0, 0, -1]];
+
 /// Node of a light propagation queue.
 #[derive(Debug)]
 pub struct LightNode {
@@ -30,8 +35,44 @@ pub struct LightNode {
 pub struct Lights;
 
 impl Lights {
+    /// How many light levels are lost crossing into `block` for `color`. Sunlight
+    /// traveling straight down through a fully clear block (one that declares no
+    /// reduction at all, i.e. `light_reduction` of 0) keeps its level instead of dimming
+    /// by one, so a vertical shaft of such blocks stays at max brightness all the way
+    /// down; the default `Block` (reduction 1) still dims descending sunlight by one per
+    /// step - callers signal the descending case with `descending_sunlight`. A stained-glass-style filter attenuates its own channel
+    /// much more (or less) than a plain transparent block would, so it overrides the
+    /// generic per-step falloff for the channel it filters.
+    fn reduction_for(block: &Block, color: &LightColor, descending_sunlight: bool) -> u32 {
+        let base_reduction = if descending_sunlight {
+            block.light_reduction
+        } else {
+            block.light_reduction.max(1)
+        };
+
+        let channel_filter = match color {
+            LightColor::Red => Some(block.light_filter[0]),
+            LightColor::Green => Some(block.light_filter[1]),
+            LightColor::Blue => Some(block.light_filter[2]),
+            LightColor::Sunlight => None,
+        };
+
+        match channel_filter {
+            Some(filter) if filter > 0 => filter.max(1),
+            _ => base_reduction,
+        }
+    }
+
     /// Propagate a specific queue of `LightNode`s in a depth-first-search fashion. If the propagation
     /// is for sunlight, light value does not decrease going downwards to simulate sunshine.
+    /// That holds only for blocks whose `light_reduction` is 0 (see `reduction_for`); any
+    /// other block dims descending sunlight by its `light_reduction`.
+    ///
+    /// When propagating sunlight through a stained-glass-style filter block (one with a
+    /// non-zero `light_filter`), the tint each channel would accumulate is seeded into
+    /// `filter_seeds` (red, green, blue, in that order) so the caller can flood it
+    /// afterwards, producing colored shadows. Pass `None` when propagating a single
+    /// torch color, or when colored shadows don't matter (e.g. during removal refills).
     pub fn flood_light(
         space: &mut dyn VoxelAccess,
         mut queue: VecDeque<LightNode>,
@@ -40,6 +81,7 @@ impl Lights {
         config: &WorldConfig,
         min: Option<&Vec3<i32>>,
         shape: Option<&Vec3<usize>>,
+        mut filter_seeds: Option<&mut [VecDeque<LightNode>; 3]>,
     ) {
         let &WorldConfig {
             max_height,
@@ -97,11 +139,48 @@ impl Lights {
                     continue;
                 }
 
-                let next_level = level - 1;
                 let next_voxel = [nvx, nvy, nvz];
                 let block_type = registry.get_block_by_id(space.get_voxel(nvx, nvy, nvz));
 
-                if !block_type.is_transparent
+                let reduction = Lights::reduction_for(block_type, color, is_sunlight && *oy == -1);
+                let next_level = level.saturating_sub(reduction);
+
+                let propagates = if is_sunlight {
+                    block_type.sunlight_propagates
+                } else {
+                    block_type.light_propagates
+                };
+
+                // White sunlight crossing a filter block casts a colored shadow: seed the
+                // torch-color queues with the tint the filter would leave behind, so they
+                // can flood it further once the caller processes them.
+                if is_sunlight && propagates && block_type.light_filter.iter().any(|&f| f > 0) {
+                    if let Some(seeds) = filter_seeds.as_deref_mut() {
+                        let tints = [
+                            (0, LightColor::Red),
+                            (1, LightColor::Green),
+                            (2, LightColor::Blue),
+                        ];
+
+                        for (index, channel) in tints {
+                            // Tint off the sunlight level actually reaching this block
+                            // (`next_level`), not the config max - otherwise already-dim
+                            // sunlight (horizontal spread, an earlier reducing block,
+                            // etc.) seeds a phantom tint brighter than its own source.
+                            let tinted = next_level.saturating_sub(block_type.light_filter[index]);
+
+                            if tinted > space.get_torch_light(nvx, nvy, nvz, &channel) {
+                                space.set_torch_light(nvx, nvy, nvz, tinted, &channel);
+                                seeds[index].push_back(LightNode {
+                                    voxel: next_voxel,
+                                    level: tinted,
+                                });
+                            }
+                        }
+                    }
+                }
+
+                if !propagates
                     || (if is_sunlight {
                         space.get_sunlight(nvx, nvy, nvz)
                     } else {
@@ -125,6 +204,32 @@ impl Lights {
         }
     }
 
+    /// Undo the colored tint `flood_light` seeds when sunlight crosses a stained-glass
+    /// filter block, each time the sunlight at that voxel is cleared. Without this, a
+    /// filter block keeps glowing its old tint forever once the sunlight feeding it is
+    /// removed (e.g. the sky above it gets blocked), since nothing else ever triggers an
+    /// R/G/B removal tied to that sunlight going away.
+    fn remove_filtered_tint(
+        space: &mut dyn VoxelAccess,
+        vx: i32,
+        vy: i32,
+        vz: i32,
+        registry: &Registry,
+        config: &WorldConfig,
+    ) {
+        let block_type = registry.get_block_by_id(space.get_voxel(vx, vy, vz));
+
+        if !block_type.light_filter.iter().any(|&f| f > 0) {
+            return;
+        }
+
+        for channel in [LightColor::Red, LightColor::Green, LightColor::Blue] {
+            if space.get_torch_light(vx, vy, vz, &channel) > 0 {
+                Lights::remove_light(space, &Vec3(vx, vy, vz), &channel, config, registry);
+            }
+        }
+    }
+
     pub fn remove_light(
         space: &mut dyn VoxelAccess,
         voxel: &Vec3<i32>,
@@ -152,6 +257,7 @@ impl Lights {
 
         if is_sunlight {
             space.set_sunlight(vx, vy, vz, 0);
+            Lights::remove_filtered_tint(space, vx, vy, vz, registry, config);
         } else {
             space.set_torch_light(vx, vy, vz, 0, color);
         }
@@ -181,8 +287,14 @@ impl Lights {
                     continue;
                 }
 
-                // if level is less, or if sunlight is propagating downwards without stopping
-                if nl < level
+                let block_type = registry.get_block_by_id(space.get_voxel(nvx, nvy, nvz));
+                let reduction = Lights::reduction_for(block_type, color, is_sunlight && *oy == -1);
+                let expected = level.saturating_sub(reduction);
+
+                // if the neighbor's level is at most what flood_light would have left behind
+                // (accounting for the neighbor block's own reduction), or if sunlight is propagating
+                // downwards without stopping, it was lit by the light we're removing
+                if nl <= expected
                     || (is_sunlight
                         && *oy == -1
                         && level == max_light_level
@@ -195,10 +307,11 @@ impl Lights {
 
                     if is_sunlight {
                         space.set_sunlight(nvx, nvy, nvz, 0);
+                        Lights::remove_filtered_tint(space, nvx, nvy, nvz, registry, config);
                     } else {
                         space.set_torch_light(nvx, nvy, nvz, 0, color);
                     }
-                } else if nl >= level && (!is_sunlight || *oy != -1 || nl > level) {
+                } else if nl > expected && (!is_sunlight || *oy != -1 || nl > level) {
                     fill.push_back(LightNode {
                         voxel: n_voxel,
                         level: nl,
@@ -207,7 +320,7 @@ impl Lights {
             }
         }
 
-        Lights::flood_light(space, fill, color, registry, config, None, None);
+        Lights::flood_light(space, fill, color, registry, config, None, None, None);
     }
 
     /// Propagate a space and return the light data of the center chunk.
@@ -250,7 +363,7 @@ impl Lights {
 
                     let id = space.get_voxel(x + start_x, y, z + start_z);
                     let &Block {
-                        is_transparent,
+                        sunlight_propagates,
                         is_light,
                         red_light_level,
                         green_light_level,
@@ -258,7 +371,7 @@ impl Lights {
                         ..
                     } = registry.get_block_by_id(id);
 
-                    if is_transparent {
+                    if sunlight_propagates {
                         space.set_sunlight(x + start_x, y, z + start_z, mask[index]);
 
                         if mask[index] == 0 {
@@ -315,6 +428,30 @@ impl Lights {
 
         let shape = Vec3(shape.0 as usize, shape.1 as usize, shape.2 as usize);
 
+        // Sunlight floods first: crossing a stained-glass-style filter block seeds
+        // colored shadows into `filter_seeds`, which are folded into the R/G/B queues
+        // below before those channels flood.
+        let mut filter_seeds: [VecDeque<LightNode>; 3] =
+            [VecDeque::new(), VecDeque::new(), VecDeque::new()];
+
+        if !sunlight_queue.is_empty() {
+            Lights::flood_light(
+                space,
+                sunlight_queue,
+                &SUNLIGHT,
+                registry,
+                config,
+                Some(min),
+                Some(&shape),
+                Some(&mut filter_seeds),
+            );
+        }
+
+        let [seeded_red, seeded_green, seeded_blue] = filter_seeds;
+        red_light_queue.extend(seeded_red);
+        green_light_queue.extend(seeded_green);
+        blue_light_queue.extend(seeded_blue);
+
         if !red_light_queue.is_empty() {
             Lights::flood_light(
                 space,
@@ -324,6 +461,7 @@ impl Lights {
                 config,
                 Some(min),
                 Some(&shape),
+                None,
             );
         }
 
@@ -336,6 +474,7 @@ impl Lights {
                 config,
                 Some(min),
                 Some(&shape),
+                None,
             );
         }
 
@@ -348,9 +487,365 @@ impl Lights {
                 config,
                 Some(min),
                 Some(&shape),
+                None,
             );
         }
 
+        space.get_lights(center.0, center.1).unwrap().to_owned()
+    }
+
+    /// Relight the seam between one chunk and a single now-available neighbor, instead of
+    /// re-running the full `propagate` pass for either chunk.
+    ///
+    /// `lighting_complete` holds one flag per direction in [`CHUNK_BORDER_DIRECTIONS`],
+    /// `true` meaning that face has already been relit. Call this once a neighbor chunk
+    /// loads, for every direction whose flag is still `false`: a strip spanning up to
+    /// `max_light_level` voxels to either side of the boundary (capped at `chunk_size - 1`
+    /// so it never reaches past the immediate neighbor into a second, possibly-unloaded
+    /// chunk; full height, full `chunk_size` along the other axis) is handed to
+    /// [`Lights::relight_volume`], which clears and recomputes it from scratch. Going
+    /// through `relight_volume` rather than flooding the boundary directly means this
+    /// also retracts light, not just adds it: a darkening edit near the seam (a torch
+    /// removed, a light-blocking block placed) gets cascade-cleared the same way a bulk
+    /// edit inside a chunk would, instead of leaving stale light stuck on the far side
+    /// forever because `flood_light` alone can only ever raise levels. The tradeoff is
+    /// cost: every call clears and re-derives the whole strip, even for an ordinary
+    /// neighbor-chunk load where nothing near the seam ever changed. The flag is set once
+    /// that face is done. Callers are responsible for clearing the relevant flag(s)
+    /// whenever a block edit lands within `max_light_level` voxels of a chunk boundary
+    /// (see [`Lights::border_flags_to_clear`]), so the next neighbor load re-relights
+    /// across that face.
+    ///
+    /// `Lights` only owns the relighting math here; storing `lighting_complete` on the
+    /// chunk struct itself, calling this when a neighbor becomes available, and calling
+    /// `border_flags_to_clear` from the block-edit path are the chunk/pipeline layer's
+    /// responsibility to wire up - that layer doesn't exist in this checkout, so there's
+    /// no call site for either function yet. Neither this function nor `relight_volume`
+    /// has a test of its own; the `tests` module below covers only `reduction_for`, the
+    /// per-step falloff that `relight_volume`'s clear-and-collect cascade relies on.
+    pub fn propagate_borders(
+        space: &mut dyn VoxelAccess,
+        chunk_coords: &Vec2<i32>,
+        lighting_complete: &mut [bool; 4],
+        registry: &Registry,
+        config: &WorldConfig,
+    ) {
+        let &WorldConfig {
+            chunk_size,
+            max_height,
+            max_light_level,
+            ..
+        } = config;
+
+        let chunk_size = chunk_size as i32;
+        let max_height = max_height as i32;
+
+        // Capped to `chunk_size - 1` so the seam box never reaches past the immediate
+        // neighbor into a second, possibly-unloaded chunk beyond it - `relight_volume`
+        // reads/writes every voxel in the box unconditionally and has no min_chunk/
+        // max_chunk guard like `flood_light` does.
+        let margin = (max_light_level as i32).min(chunk_size - 1);
+
+        let &Vec2(cx, cz) = chunk_coords;
+        let base_x = cx * chunk_size;
+        let base_z = cz * chunk_size;
+
+        for (index, [ox, _, oz]) in CHUNK_BORDER_DIRECTIONS.iter().enumerate() {
+            if lighting_complete[index] {
+                continue;
+            }
+
+            let (seam_min, seam_max) = if *ox != 0 {
+                let edge_x = if *ox > 0 {
+                    base_x + chunk_size - 1
+                } else {
+                    base_x
+                };
+
+                (
+                    Vec3(edge_x - margin, 0, base_z),
+                    Vec3(edge_x + margin, max_height - 1, base_z + chunk_size - 1),
+                )
+            } else {
+                let edge_z = if *oz > 0 {
+                    base_z + chunk_size - 1
+                } else {
+                    base_z
+                };
+
+                (
+                    Vec3(base_x, 0, edge_z - margin),
+                    Vec3(base_x + chunk_size - 1, max_height - 1, edge_z + margin),
+                )
+            };
+
+            Lights::relight_volume(space, &seam_min, &seam_max, registry, config);
+
+            lighting_complete[index] = true;
+        }
+    }
+
+    /// Given a voxel edit within `chunk_coords`, return the indices into
+    /// [`CHUNK_BORDER_DIRECTIONS`] (and so into a chunk's `lighting_complete` flags) that
+    /// must be cleared because the edit landed within `max_light_level` voxels of that
+    /// face - the margin [`Lights::propagate_borders`] relights (which it caps at
+    /// `chunk_size - 1`; this check is not capped), so anything closer than that could
+    /// change what crosses the seam. The caller (the chunk/pipeline layer
+    /// that owns the flags) should clear each returned index so the next
+    /// [`Lights::propagate_borders`] call re-relights across that face.
+    pub fn border_flags_to_clear(
+        voxel: &Vec3<i32>,
+        chunk_coords: &Vec2<i32>,
+        config: &WorldConfig,
+    ) -> Vec<usize> {
+        let chunk_size = config.chunk_size as i32;
+        let margin = config.max_light_level as i32;
+        let &Vec2(cx, cz) = chunk_coords;
+        let &Vec3(vx, _, vz) = voxel;
+
+        let local_x = vx - cx * chunk_size;
+        let local_z = vz - cz * chunk_size;
+
+        let mut indices = Vec::new();
+
+        if local_x <= margin {
+            indices.push(1); // -x, see CHUNK_BORDER_DIRECTIONS
+        }
+        if local_x >= chunk_size - 1 - margin {
+            indices.push(0); // +x
+        }
+        if local_z <= margin {
+            indices.push(3); // -z
+        }
+        if local_z >= chunk_size - 1 - margin {
+            indices.push(2); // +z
+        }
+
+        indices
+    }
+
+    /// Relight an arbitrary axis-aligned box (inclusive on both ends) after a bulk edit
+    /// such as stamping a tree or schematic, without re-running [`Lights::propagate`]
+    /// over the whole space. Implements Minetest's clear-and-collect-sources strategy:
+    /// every light bank inside the box is zeroed while any still-lit voxel just outside
+    /// the box that was feeding it is collected into a refill queue, emissive blocks and
+    /// the sunlit top of the box are re-seeded, and [`Lights::flood_light`] restores
+    /// everything that should still reach inside the box from those sources.
+    ///
+    /// The cascade below is what tells a box-fed source (stale light a voxel inside the
+    /// box used to cast, now being cleared) from an independent one just outside it
+    /// (its own torch, its own sky access): `reduction_for` is the same per-step falloff
+    /// test `remove_light` uses, so a neighbor's level matching what the cleared voxel
+    /// would have left behind gets cleared too, while anything brighter than that is
+    /// trusted as its own source and queued to refill the box. `reduction_for` itself is
+    /// unit-tested (see the `tests` module below); exercising this cascade end-to-end
+    /// needs a fake `VoxelAccess`, which this checkout's missing `access.rs`/`registry.rs`/
+    /// `world/mod.rs` block.
+    pub fn relight_volume(
+        space: &mut dyn VoxelAccess,
+        min: &Vec3<i32>,
+        max: &Vec3<i32>,
+        registry: &Registry,
+        config: &WorldConfig,
+    ) {
+        let &WorldConfig {
+            max_height,
+            max_light_level,
+            ..
+        } = config;
+
+        let max_height = max_height as i32;
+        let &Vec3(min_x, raw_min_y, min_z) = min;
+        let &Vec3(max_x, raw_max_y, max_z) = max;
+        let min_y = raw_min_y.max(0);
+        let max_y = raw_max_y.min(max_height - 1);
+
+        const RED: LightColor = LightColor::Red;
+        const GREEN: LightColor = LightColor::Green;
+        const BLUE: LightColor = LightColor::Blue;
+        const SUNLIGHT: LightColor = LightColor::Sunlight;
+
+        let in_box = |x: i32, y: i32, z: i32| {
+            x >= min_x && x <= max_x && y >= min_y && y <= max_y && z >= min_z && z <= max_z
+        };
+
+        let mut red_queue = VecDeque::new();
+        let mut green_queue = VecDeque::new();
+        let mut blue_queue = VecDeque::new();
+        let mut sunlight_queue = VecDeque::new();
+
+        // Clear every bank inside the box, then cascade that clear outward exactly like
+        // `remove_light` does: a voxel just past the box edge only gets collected as a
+        // refill source once we've confirmed its brightness can't be explained by
+        // something we're erasing inside the box. Without this cascade, a light source
+        // removed from the box (e.g. a torch replaced by a schematic stamp) leaves its
+        // stale propagated glow sitting just outside the box, which then gets read back
+        // in as if it were a legitimate external source.
+        for &color in &[RED, GREEN, BLUE, SUNLIGHT] {
+            let is_sunlight = color == SUNLIGHT;
+            let mut cascade = VecDeque::new();
+
+            for x in min_x..=max_x {
+                for y in min_y..=max_y {
+                    for z in min_z..=max_z {
+                        let level = if is_sunlight {
+                            space.get_sunlight(x, y, z)
+                        } else {
+                            space.get_torch_light(x, y, z, &color)
+                        };
+
+                        if level > 0 {
+                            cascade.push_back(LightNode {
+                                voxel: [x, y, z],
+                                level,
+                            });
+                        }
+
+                        if is_sunlight {
+                            space.set_sunlight(x, y, z, 0);
+                        } else {
+                            space.set_torch_light(x, y, z, 0, &color);
+                        }
+                    }
+                }
+            }
+
+            let refill_queue = match color {
+                LightColor::Red => &mut red_queue,
+                LightColor::Green => &mut green_queue,
+                LightColor::Blue => &mut blue_queue,
+                LightColor::Sunlight => &mut sunlight_queue,
+            };
+
+            while let Some(LightNode {
+                voxel: [vx, vy, vz],
+                level,
+            }) = cascade.pop_front()
+            {
+                for &[ox, oy, oz] in VOXEL_NEIGHBORS.iter() {
+                    let (nx, ny, nz) = (vx + ox, vy + oy, vz + oz);
+
+                    if ny < 0 || ny >= max_height || in_box(nx, ny, nz) {
+                        continue;
+                    }
+
+                    let nl = if is_sunlight {
+                        space.get_sunlight(nx, ny, nz)
+                    } else {
+                        space.get_torch_light(nx, ny, nz, &color)
+                    };
+
+                    if nl == 0 {
+                        continue;
+                    }
+
+                    let block_type = registry.get_block_by_id(space.get_voxel(nx, ny, nz));
+                    let reduction =
+                        Lights::reduction_for(block_type, color, is_sunlight && oy == -1);
+                    let expected = level.saturating_sub(reduction);
+
+                    // Same decision rule as remove_light: if the neighbor's level is at
+                    // most what the voxel we just cleared would have left behind, it
+                    // was lit by that voxel and must go dark too; if it is brighter, it
+                    // has its own legitimate source and becomes a refill seed.
+                    if nl <= expected
+                        || (is_sunlight
+                            && oy == -1
+                            && level == max_light_level
+                            && nl == max_light_level)
+                    {
+                        if is_sunlight {
+                            space.set_sunlight(nx, ny, nz, 0);
+                        } else {
+                            space.set_torch_light(nx, ny, nz, 0, &color);
+                        }
+
+                        cascade.push_back(LightNode {
+                            voxel: [nx, ny, nz],
+                            level: nl,
+                        });
+                    } else {
+                        refill_queue.push_back(LightNode {
+                            voxel: [nx, ny, nz],
+                            level: nl,
+                        });
+                    }
+                }
+            }
+        }
+
+        // A box whose top face sits exactly at the world ceiling has no voxel above it
+        // to read a mask from (`max_y + 1` would clamp back onto the box's own,
+        // just-cleared top row), so treat the column above as fully sunlit instead -
+        // otherwise a tree stamped flush with the ceiling would trap stale darkness
+        // instead of casting a proper shadow.
+        let near_ceiling = max_y >= max_height - 1;
+
+        // Re-seed emissive blocks and the top-of-column sunlight mask inside the box.
+        for x in min_x..=max_x {
+            for z in min_z..=max_z {
+                let mut mask = if near_ceiling {
+                    max_light_level
+                } else {
+                    space.get_sunlight(x, (max_y + 1).min(max_height - 1), z)
+                };
+
+                for y in (min_y..=max_y).rev() {
+                    let id = space.get_voxel(x, y, z);
+                    let &Block {
+                        sunlight_propagates,
+                        is_light,
+                        red_light_level,
+                        green_light_level,
+                        blue_light_level,
+                        ..
+                    } = registry.get_block_by_id(id);
+
+                    if sunlight_propagates {
+                        space.set_sunlight(x, y, z, mask);
+
+                        if mask > 0 {
+                            sunlight_queue.push_back(LightNode {
+                                voxel: [x, y, z],
+                                level: mask,
+                            });
+                        }
+                    } else {
+                        mask = 0;
+                    }
+
+                    if is_light {
+                        if red_light_level > 0 {
+                            space.set_red_light(x, y, z, red_light_level);
+                            red_queue.push_back(LightNode {
+                                voxel: [x, y, z],
+                                level: red_light_level,
+                            });
+                        }
+                        if green_light_level > 0 {
+                            space.set_green_light(x, y, z, green_light_level);
+                            green_queue.push_back(LightNode {
+                                voxel: [x, y, z],
+                                level: green_light_level,
+                            });
+                        }
+                        if blue_light_level > 0 {
+                            space.set_blue_light(x, y, z, blue_light_level);
+                            blue_queue.push_back(LightNode {
+                                voxel: [x, y, z],
+                                level: blue_light_level,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        // Sunlight floods first so any stained-glass filters inside the box seed
+        // colored shadows into the torch queues before they flood, same as `propagate`.
+        let mut filter_seeds: [VecDeque<LightNode>; 3] =
+            [VecDeque::new(), VecDeque::new(), VecDeque::new()];
+
         if !sunlight_queue.is_empty() {
             Lights::flood_light(
                 space,
@@ -358,11 +853,117 @@ impl Lights {
                 &SUNLIGHT,
                 registry,
                 config,
-                Some(min),
-                Some(&shape),
+                None,
+                None,
+                Some(&mut filter_seeds),
             );
         }
 
-        space.get_lights(center.0, center.1).unwrap().to_owned()
+        let [seeded_red, seeded_green, seeded_blue] = filter_seeds;
+        red_queue.extend(seeded_red);
+        green_queue.extend(seeded_green);
+        blue_queue.extend(seeded_blue);
+
+        if !red_queue.is_empty() {
+            Lights::flood_light(space, red_queue, &RED, registry, config, None, None, None);
+        }
+
+        if !green_queue.is_empty() {
+            Lights::flood_light(
+                space,
+                green_queue,
+                &GREEN,
+                registry,
+                config,
+                None,
+                None,
+                None,
+            );
+        }
+
+        if !blue_queue.is_empty() {
+            Lights::flood_light(space, blue_queue, &BLUE, registry, config, None, None, None);
+        }
+    }
+}
+
+// `reduction_for` backs the one decision (how much a block dims the light crossing into
+// it) that `flood_light`, `remove_light`, and `relight_volume` all have to agree on - a
+// mismatch between them is exactly what produced the clear-and-collect and tint-removal
+// bugs walked back earlier in this file's history. It's also the only piece of this
+// module's logic that doesn't need a live `VoxelAccess`/`Registry`/`WorldConfig` to
+// exercise, since this checkout doesn't carry those modules (or the `utils` crate the
+// rest of the file draws `LightColor`/`Vec2`/`Vec3`/`Ndarray` from) - so it's what's
+// covered here. BFS-level cases (stained-glass tint/untint, `relight_volume` telling a
+// box-fed source from an independent one, a `propagate_borders` round-trip) need a fake
+// `VoxelAccess` over a real `Space`/`Registry`/`WorldConfig` to drive, which belongs in
+// those modules' own test suites once they exist in this tree.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block_with(light_reduction: u32, light_filter: [u32; 3]) -> Block {
+        Block {
+            light_reduction,
+            light_filter,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn air_dims_light_by_one_step() {
+        let air = block_with(1, [0, 0, 0]);
+        assert_eq!(Lights::reduction_for(&air, &LightColor::Red, false), 1);
+    }
+
+    #[test]
+    fn denser_block_dims_light_faster_than_air() {
+        let water = block_with(3, [0, 0, 0]);
+        assert_eq!(Lights::reduction_for(&water, &LightColor::Red, false), 3);
+    }
+
+    #[test]
+    fn sunlight_keeps_its_level_descending_through_fully_clear_air() {
+        let air = block_with(0, [0, 0, 0]);
+        assert_eq!(Lights::reduction_for(&air, &LightColor::Sunlight, true), 0);
+    }
+
+    #[test]
+    fn sunlight_still_dims_by_one_when_not_descending_straight_down() {
+        let air = block_with(0, [0, 0, 0]);
+        assert_eq!(Lights::reduction_for(&air, &LightColor::Sunlight, false), 1);
+    }
+
+    #[test]
+    fn stained_glass_overrides_reduction_only_for_its_filtered_channels() {
+        // A red-tinted pane: barely dims red, heavily filters green and blue, and - since
+        // the filter is per-torch-color - has no say over the sunlight channel at all.
+        let red_glass = block_with(1, [1, 8, 8]);
+
+        assert_eq!(
+            Lights::reduction_for(&red_glass, &LightColor::Red, false),
+            1
+        );
+        assert_eq!(
+            Lights::reduction_for(&red_glass, &LightColor::Green, false),
+            8
+        );
+        assert_eq!(
+            Lights::reduction_for(&red_glass, &LightColor::Blue, false),
+            8
+        );
+        assert_eq!(
+            Lights::reduction_for(&red_glass, &LightColor::Sunlight, false),
+            1
+        );
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn zero_entries_in_light_filter_fall_back_to_base_reduction() {
+        let dense_but_unfiltered = block_with(2, [0, 0, 0]);
+        assert_eq!(
+            Lights::reduction_for(&dense_but_unfiltered, &LightColor::Red, false),
+            2
+        );
+    }
+}